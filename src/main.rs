@@ -1,7 +1,15 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use lazy_static::lazy_static;
-use std::{collections::HashMap, sync::Mutex, time::Instant};
-use tabled::{locator::ByColumnName, style::HorizontalLine, Alignment, Modify, Table, Tabled};
+use owo_colors::OwoColorize;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    io::IsTerminal,
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+use tabled::{style::HorizontalLine, Table, Tabled};
 
 #[derive(Parser, Debug)]
 struct Cli {
@@ -10,6 +18,52 @@ struct Cli {
 
     #[arg(short, long)]
     debug: bool,
+
+    /// Bypass the on-disk cache and always fetch from the network
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Output format for query results
+    #[arg(long, value_enum, default_value = "table", global = true)]
+    format: OutputFormat,
+
+    /// Disable colored output (also disabled automatically when stdout is not a TTY)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Language for stop and route names
+    #[arg(long, value_enum, default_value = "tc", global = true)]
+    lang: Lang,
+}
+
+/// Which localized field to read from the KMB API: traditional Chinese,
+/// English, or simplified Chinese.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Lang {
+    Tc,
+    En,
+    Sc,
+}
+
+impl Lang {
+    /// The suffix the KMB API appends to localized field names, e.g. `tc` in
+    /// `name_tc` / `orig_tc`.
+    fn suffix(&self) -> &'static str {
+        match self {
+            Lang::Tc => "tc",
+            Lang::En => "en",
+            Lang::Sc => "sc",
+        }
+    }
+}
+
+/// How query results are rendered. `table` is the human-readable default;
+/// `json` and `csv` are meant for scripting and downstream tooling.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
 }
 
 #[derive(Subcommand, Debug)]
@@ -40,13 +94,40 @@ enum Commands {
         /// Route service type
         #[arg(short, long, default_value = "1")]
         service_type: i64,
+
+        /// Keep polling the ETA endpoint and redraw the table in place,
+        /// turning the terminal into a live departure board. Exit with Ctrl-C.
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Seconds to wait between refreshes while in `--watch` mode
+        #[arg(long, default_value = "10")]
+        interval: u64,
     },
 
     /// Display all route info. Example `kmb-eta-cli all | fzf`
     All,
+
+    /// Fuzzy-search stop names and list every route passing through each match
+    Stop {
+        /// Stop name to search for, e.g. `旺角` or `mong kok`
+        query: String,
+    },
+
+    /// Manage the on-disk cache of stop names and route lists
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommands,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheCommands {
+    /// Remove all cached stop-name and route data
+    Clear,
 }
 
-#[derive(Tabled, Clone)]
+#[derive(Tabled, Clone, Serialize, Deserialize)]
 struct RouteInfo {
     route: String,
     service_type: i64,
@@ -55,19 +136,48 @@ struct RouteInfo {
     dest: String,
 }
 
-#[derive(Tabled, Clone)]
+#[derive(Tabled, Clone, Serialize, Deserialize)]
 struct StopIdName {
     stop_id: String,
     stop_name: String,
 }
 
-#[derive(Tabled, Clone)]
+/// One entry of the inverted `stop_id -> serving routes` index built from the
+/// bulk `route-stop` listing.
+#[derive(Clone, Serialize, Deserialize)]
+struct StopRoute {
+    route: String,
+    direction: String,
+    service_type: i64,
+    seq: i64,
+}
+
+#[derive(Tabled, Serialize)]
+struct StopRouteInfo {
+    stop_id: String,
+    stop_name: String,
+    route: String,
+    direction: String,
+    service_type: i64,
+    seq: i64,
+}
+
+#[derive(Tabled, Clone, Serialize)]
 struct RouteEtaInfo {
     seq: String,
     stop_name: String,
     t1: String,
     t2: String,
     t3: String,
+    /// Raw ETA offsets in seconds (relative to the API's generated timestamp),
+    /// emitted alongside the human `t1/t2/t3` strings for `json`/`csv` output.
+    /// Hidden from the table; `None` when the ETA is blank/unavailable.
+    #[tabled(skip)]
+    d1: Option<i64>,
+    #[tabled(skip)]
+    d2: Option<i64>,
+    #[tabled(skip)]
+    d3: Option<i64>,
 }
 
 struct HKGovAPI {}
@@ -79,6 +189,136 @@ impl HKGovAPI {
     const ROUTE_URL: &str = "v1/transport/kmb/route";
 }
 
+/// Filenames and freshness policy for the on-disk cache. Stop names and route
+/// lists change rarely, so a day-long TTL makes repeat queries near-instant.
+struct Cache {}
+impl Cache {
+    const STOP_ROUTES_FILE: &str = "stop_routes.json";
+    const TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+    // stop names and routes are cached per language so switching `--lang`
+    // doesn't serve stale entries from another locale
+    fn stop_names_file(lang: Lang) -> String {
+        format!("stop_names_{}.json", lang.suffix())
+    }
+
+    fn routes_file(lang: Lang) -> String {
+        format!("routes_{}.json", lang.suffix())
+    }
+}
+
+/// A cached payload stamped with the wall-clock time it was written, so the
+/// TTL can be checked on read.
+#[derive(Serialize, Deserialize)]
+struct Cached<T> {
+    generated_at: u64,
+    data: T,
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    directories::ProjectDirs::from("", "", "kmb-eta-cli").map(|d| d.cache_dir().to_path_buf())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Read a cached payload if it exists and is younger than `ttl`. Any error
+/// (missing file, corrupt data, clock skew) is treated as a cache miss.
+fn read_cache<T: DeserializeOwned>(file: &str, ttl: Duration) -> Option<T> {
+    let path = cache_dir()?.join(file);
+    let bytes = std::fs::read(path).ok()?;
+    let cached: Cached<T> = serde_json::from_slice(&bytes).ok()?;
+    // a payload stamped in the future (clock skew) has no valid age: a miss
+    let age = unix_now().checked_sub(cached.generated_at)?;
+    if age <= ttl.as_secs() {
+        Some(cached.data)
+    } else {
+        None
+    }
+}
+
+/// Write a payload to the cache, best-effort — failures (e.g. an unwritable
+/// cache dir) are silently ignored so caching never breaks a query.
+fn write_cache<T: Serialize>(file: &str, data: &T) {
+    let Some(dir) = cache_dir() else { return };
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    let cached = Cached {
+        generated_at: unix_now(),
+        data,
+    };
+    if let Ok(bytes) = serde_json::to_vec(&cached) {
+        let _ = std::fs::write(dir.join(file), bytes);
+    }
+}
+
+fn clear_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(dir) = cache_dir() else {
+        return Ok(());
+    };
+    let mut files = vec![Cache::STOP_ROUTES_FILE.to_string()];
+    for lang in [Lang::Tc, Lang::En, Lang::Sc] {
+        files.push(Cache::stop_names_file(lang));
+        files.push(Cache::routes_file(lang));
+    }
+
+    for file in files {
+        let path = dir.join(file);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Serialize rows as pretty-printed JSON to stdout.
+fn emit_json<T: Serialize>(rows: &[T]) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", serde_json::to_string_pretty(rows)?);
+    Ok(())
+}
+
+/// Serialize rows as CSV (with a header row) to stdout.
+fn emit_csv<T: Serialize>(rows: &[T]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Widest an ETA cell gets (`999m 59s`), used to right-justify cells before
+/// coloring so the column stays aligned regardless of `tabled`'s ANSI handling.
+const ETA_CELL_WIDTH: usize = 8;
+
+/// Style an ETA cell by urgency: red/bold when the bus is leaving or under two
+/// minutes away, yellow for two-to-five minutes, green beyond that, and dim for
+/// blank/unavailable ETAs. Returns the plain string when `color` is off.
+///
+/// The text is right-justified to [`ETA_CELL_WIDTH`] *before* the ANSI escapes
+/// are applied. `tabled` (without its color feature) measures cell width by
+/// counting the raw bytes, escapes included, so coloring first then letting the
+/// table right-align would over-widen and misalign the column; pre-justifying
+/// keeps the visible digits lined up either way.
+fn colorize_eta(repr: &str, diff: Option<i64>, color: bool) -> String {
+    let cell = format!("{:>width$}", repr, width = ETA_CELL_WIDTH);
+    if !color {
+        return cell;
+    }
+
+    match diff {
+        None => cell.dimmed().to_string(),
+        Some(d) if d < 120 => cell.red().bold().to_string(),
+        Some(d) if d <= 300 => cell.yellow().to_string(),
+        Some(_) => cell.green().to_string(),
+    }
+}
+
 lazy_static!(
     // use singleton reqwest client instead of calling reqwest::get(...)
     // massive performance boost
@@ -89,9 +329,48 @@ lazy_static!(
 
     // key: stop_id, value: stopIdName struct
     static ref STOP_ID_NAMES: Mutex<HashMap<String, StopIdName>> = Mutex::new(HashMap::new());
+
+    // key: stop_id, value: every route/direction/service_type/seq passing through it
+    static ref STOP_ROUTES: Mutex<HashMap<String, Vec<StopRoute>>> = Mutex::new(HashMap::new());
 );
 
-async fn load_names() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// How many of the best-matching stops to display for a `stop` query.
+const STOP_SEARCH_LIMIT: usize = 10;
+
+/// Levenshtein edit distance between two strings, used to rank stop names
+/// against a user-typed query.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0usize; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j + 1] + 1).min(cur[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+async fn load_names(
+    no_cache: bool,
+    lang: Lang,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let cache_file = Cache::stop_names_file(lang);
+    if !no_cache {
+        if let Some(map) = read_cache::<HashMap<String, StopIdName>>(&cache_file, Cache::TTL) {
+            *STOP_ID_NAMES.lock().unwrap() = map;
+            return Ok(());
+        }
+    }
+
+    let name_key = format!("name_{}", lang.suffix());
     let req_url = format!("{}/{}", HKGovAPI::BASE_URL, HKGovAPI::STOP_URL);
 
     let body = REQWEST_CLIENT
@@ -109,87 +388,213 @@ async fn load_names() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         .iter()
         .for_each(|data| {
             let stop_id = data["stop"].as_str().unwrap();
-            let name_tc = data["name_tc"].as_str().unwrap();
+            let name = data[name_key.as_str()].as_str().unwrap();
 
             mutext_stop_id_names.insert(
                 stop_id.to_string(),
                 StopIdName {
                     stop_id: stop_id.to_string(),
-                    stop_name: name_tc.to_string(),
+                    stop_name: name.to_string(),
                 },
             );
         });
 
+    if !no_cache {
+        write_cache(&cache_file, &*mutext_stop_id_names);
+    }
+
     Ok(())
 }
 
-async fn search_route_eta(
+/// Parse the ordered `(seq, stop_id)` list out of a `route-stop` response body.
+fn parse_route_ids(body: &serde_json::Value) -> Vec<(i64, String)> {
+    body["data"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .fold(vec![], |mut cur, data| {
+            let seq = data["seq"].as_str().unwrap().parse::<i64>().unwrap();
+            let stop_id = data["stop"].as_str().unwrap();
+
+            cur.push((seq, String::from(stop_id)));
+            cur
+        })
+}
+
+/// Fetch the ordered `(seq, stop_id)` list for a route/direction/service_type.
+///
+/// This is the `route-stop` mapping, which is stable across polls — in
+/// `--watch` mode it is fetched once and reused while only the ETA endpoint
+/// is re-queried each cycle.
+async fn fetch_route_stop_ids(
     route: &str,
     direction: &str,
     service_type: i64,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<Vec<(i64, String)>, Box<dyn std::error::Error>> {
     // make sure route exists
-    search_route_info(route, false, Some(direction), Some(service_type)).await?;
+    search_route_info(
+        route,
+        false,
+        Some(direction),
+        Some(service_type),
+        OutputFormat::Table,
+    )
+    .await?;
+
+    let req_url = format!(
+        "{}/{}/{}/{}/{}",
+        HKGovAPI::BASE_URL,
+        HKGovAPI::ROUTE_STOP_URL,
+        route,
+        direction,
+        service_type
+    );
 
-    let t_route = route.to_string();
-    let t_direction = direction.to_string();
-    let t_service_type = service_type;
-    let task_route_ids = tokio::spawn(async move {
-        let req_url = format!(
-            "{}/{}/{}/{}/{}",
-            HKGovAPI::BASE_URL,
-            HKGovAPI::ROUTE_STOP_URL,
-            t_route,
-            t_direction,
-            t_service_type
-        );
+    let body = REQWEST_CLIENT
+        .get(req_url)
+        .send()
+        .await?
+        .json::<serde_json::Value>()
+        .await?;
+
+    Ok(parse_route_ids(&body))
+}
 
+async fn search_route_eta(
+    route: &str,
+    direction: &str,
+    service_type: i64,
+    format: OutputFormat,
+    color: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // make sure route exists (cheap, local lookup)
+    search_route_info(
+        route,
+        false,
+        Some(direction),
+        Some(service_type),
+        OutputFormat::Table,
+    )
+    .await?;
+
+    // fire the `route-stop` and `route-eta` fetches concurrently — mirroring
+    // the baseline's two `tokio::spawn`s — so the one-shot path pays a single
+    // round-trip instead of awaiting the stop-id fetch before the ETA fetch.
+    let route_stop_url = format!(
+        "{}/{}/{}/{}/{}",
+        HKGovAPI::BASE_URL,
+        HKGovAPI::ROUTE_STOP_URL,
+        route,
+        direction,
+        service_type
+    );
+    let task_route_ids = tokio::spawn(async move {
         let body = REQWEST_CLIENT
-            .get(req_url)
+            .get(route_stop_url)
             .send()
             .await?
             .json::<serde_json::Value>()
             .await?;
-
         Ok::<serde_json::Value, Box<dyn std::error::Error + Send + Sync>>(body)
     });
 
-    let t_route = route.to_string();
-    let t_service_type = service_type;
-    let task_stop_eta = tokio::spawn(async move {
-        let req_url = format!(
-            "{}/{}/{}/{}",
-            HKGovAPI::BASE_URL,
-            HKGovAPI::ROUTE_ETA_URL,
-            t_route,
-            t_service_type
-        );
-
+    let eta_url = format!(
+        "{}/{}/{}/{}",
+        HKGovAPI::BASE_URL,
+        HKGovAPI::ROUTE_ETA_URL,
+        route,
+        service_type
+    );
+    let task_eta = tokio::spawn(async move {
         let body = REQWEST_CLIENT
-            .get(req_url)
+            .get(eta_url)
             .send()
             .await?
             .json::<serde_json::Value>()
             .await?;
-
         Ok::<serde_json::Value, Box<dyn std::error::Error + Send + Sync>>(body)
     });
 
-    let body_route_ids = task_route_ids.await?.unwrap();
-    let body_stop_eta = task_stop_eta.await?.unwrap();
+    // the spawned tasks yield `Box<dyn Error + Send + Sync>`, which does not
+    // coerce to this fn's `Box<dyn Error>` through `?` on its own — map it over
+    // explicitly before propagating.
+    let body_route_ids = task_route_ids
+        .await?
+        .map_err(|e| -> Box<dyn std::error::Error> { e })?;
+    let body_stop_eta = task_eta
+        .await?
+        .map_err(|e| -> Box<dyn std::error::Error> { e })?;
 
-    let route_ids = body_route_ids["data"]
-        .as_array()
-        .unwrap_or(&vec![])
-        .iter()
-        .fold(vec![], |mut cur, data| {
-            let seq = data["seq"].as_str().unwrap().parse::<i64>().unwrap();
-            let stop_id = data["stop"].as_str().unwrap();
+    let route_ids = parse_route_ids(&body_route_ids);
+    render_eta_body(&body_stop_eta, direction, &route_ids, format, color)
+}
 
-            cur.push((seq, String::from(stop_id)));
-            cur
-        });
+/// Poll the ETA endpoint in a loop, clearing the screen and redrawing the
+/// `RouteEtaInfo` table in place every `interval` seconds. The `route-stop`
+/// mapping is resolved once up front; each cycle re-queries only the ETA
+/// endpoint so the countdown stays accurate. Exits cleanly on Ctrl-C.
+async fn watch_route_eta(
+    route: &str,
+    direction: &str,
+    service_type: i64,
+    interval: u64,
+    format: OutputFormat,
+    color: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let route_ids = fetch_route_stop_ids(route, direction, service_type).await?;
+
+    loop {
+        // clear the screen and move the cursor home before each redraw
+        print!("\x1b[2J\x1b[H");
+        render_route_eta(route, direction, service_type, &route_ids, format, color).await?;
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(Duration::from_secs(interval)) => {}
+        }
+    }
+
+    Ok(())
+}
 
+/// Query the ETA endpoint once and render the `RouteEtaInfo` table for the
+/// given (already resolved) `route-stop` mapping. Used by `--watch`, which
+/// re-queries only this endpoint each cycle.
+async fn render_route_eta(
+    route: &str,
+    direction: &str,
+    service_type: i64,
+    route_ids: &[(i64, String)],
+    format: OutputFormat,
+    color: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let req_url = format!(
+        "{}/{}/{}/{}",
+        HKGovAPI::BASE_URL,
+        HKGovAPI::ROUTE_ETA_URL,
+        route,
+        service_type
+    );
+
+    let body_stop_eta = REQWEST_CLIENT
+        .get(req_url)
+        .send()
+        .await?
+        .json::<serde_json::Value>()
+        .await?;
+
+    render_eta_body(&body_stop_eta, direction, route_ids, format, color)
+}
+
+/// Render the `RouteEtaInfo` table from an already-fetched `route-eta` body and
+/// the resolved `route-stop` mapping.
+fn render_eta_body(
+    body_stop_eta: &serde_json::Value,
+    direction: &str,
+    route_ids: &[(i64, String)],
+    format: OutputFormat,
+    color: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
     let mut stop_eta = HashMap::new();
     let parse_timestmap =
         |timestamp_val: &serde_json::Value| -> Result<i64, Box<dyn std::error::Error>> {
@@ -228,65 +633,97 @@ async fn search_route_eta(
             let eta_seq = data["eta_seq"].as_i64().unwrap();
             let eta_timestamp = parse_timestmap(&data["eta"]);
 
-            let eta_repr = match eta_timestamp {
+            // store both the human-readable string and the raw offset in
+            // seconds (`None` when the ETA is blank) so `json`/`csv` output can
+            // expose the numbers to downstream tooling.
+            let (eta_repr, eta_diff) = match eta_timestamp {
                 Ok(t) => {
                     let eta_diff = t - api_timestamp;
                     if eta_diff > 0 {
                         // spare 3 chars for minutes, 2 chars for seconds
-                        format!("{:>3}m {:>2}s", eta_diff / 60, eta_diff % 60,)
+                        (
+                            format!("{:>3}m {:>2}s", eta_diff / 60, eta_diff % 60,),
+                            Some(eta_diff),
+                        )
                     } else {
-                        "LEAVING".to_string()
+                        ("LEAVING".to_string(), Some(eta_diff))
                     }
                 }
-                Err(_) => "".to_string(),
+                Err(_) => ("".to_string(), None),
             };
 
-            stop_eta.insert((seq, eta_seq), eta_repr);
+            stop_eta.insert((seq, eta_seq), (eta_repr, eta_diff));
         });
 
     let mutex_stop_id_names = STOP_ID_NAMES.lock().unwrap();
     let mut output = vec![];
 
-    let empty_eta = &"".to_string();
-    for (ref_seq, stop_id) in &route_ids {
+    let empty_eta = &("".to_string(), None);
+    for (ref_seq, stop_id) in route_ids {
         let seq = *ref_seq;
 
         let stop_name = &mutex_stop_id_names.get(stop_id).unwrap().stop_name;
 
-        let first_eta = stop_eta.get(&(seq, 1)).unwrap_or(empty_eta);
-        let second_eta = stop_eta.get(&(seq, 2)).unwrap_or(empty_eta);
-        let third_eta = stop_eta.get(&(seq, 3)).unwrap_or(empty_eta);
+        let (t1, d1) = stop_eta.get(&(seq, 1)).unwrap_or(empty_eta);
+        let (t2, d2) = stop_eta.get(&(seq, 2)).unwrap_or(empty_eta);
+        let (t3, d3) = stop_eta.get(&(seq, 3)).unwrap_or(empty_eta);
 
         output.push(RouteEtaInfo {
             seq: seq.to_string(),
             stop_name: stop_name.to_string(),
-            t1: first_eta.to_string(),
-            t2: second_eta.to_string(),
-            t3: third_eta.to_string(),
+            t1: t1.to_string(),
+            t2: t2.to_string(),
+            t3: t3.to_string(),
+            d1: *d1,
+            d2: *d2,
+            d3: *d3,
         })
-        // output.push((seq.to_string(), stop_name, first_eta, second_eta, third_eta));
     }
 
-    let mut table = Table::new(output);
-    table
-        .with(
-            tabled::Style::modern()
-                .off_horizontal()
-                .horizontals([HorizontalLine::new(
-                    1,
-                    tabled::Style::modern().get_horizontal(),
-                )]),
-        )
-        .with(Modify::new(ByColumnName::new("t1")).with(Alignment::right()))
-        .with(Modify::new(ByColumnName::new("t2")).with(Alignment::right()))
-        .with(Modify::new(ByColumnName::new("t3")).with(Alignment::right()));
-
-    println!("{}", table);
+    match format {
+        OutputFormat::Json => emit_json(&output)?,
+        OutputFormat::Csv => emit_csv(&output)?,
+        OutputFormat::Table => {
+            // right-justify (to a fixed width) and color the ETA cells before
+            // rendering; the cells carry their own alignment so we don't ask
+            // `tabled` to re-align ANSI-colored text by byte width.
+            for row in &mut output {
+                row.t1 = colorize_eta(&row.t1, row.d1, color);
+                row.t2 = colorize_eta(&row.t2, row.d2, color);
+                row.t3 = colorize_eta(&row.t3, row.d3, color);
+            }
+
+            let mut table = Table::new(output);
+            table.with(
+                tabled::Style::modern()
+                    .off_horizontal()
+                    .horizontals([HorizontalLine::new(
+                        1,
+                        tabled::Style::modern().get_horizontal(),
+                    )]),
+            );
+
+            println!("{}", table);
+        }
+    }
 
     Ok(())
 }
 
-async fn load_routes() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+async fn load_routes(
+    no_cache: bool,
+    lang: Lang,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let cache_file = Cache::routes_file(lang);
+    if !no_cache {
+        if let Some(map) = read_cache::<HashMap<String, Vec<RouteInfo>>>(&cache_file, Cache::TTL) {
+            *ROUTES.lock().unwrap() = map;
+            return Ok(());
+        }
+    }
+
+    let orig_key = format!("orig_{}", lang.suffix());
+    let dest_key = format!("dest_{}", lang.suffix());
     let req_url = format!("{}/{}", HKGovAPI::BASE_URL, HKGovAPI::ROUTE_URL,);
 
     let body = REQWEST_CLIENT
@@ -304,8 +741,8 @@ async fn load_routes() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             .unwrap()
             .parse::<i64>()
             .unwrap();
-        let orig_tc = data["orig_tc"].as_str().unwrap();
-        let dest_tc = data["dest_tc"].as_str().unwrap();
+        let orig = data[orig_key.as_str()].as_str().unwrap();
+        let dest = data[dest_key.as_str()].as_str().unwrap();
         let bound = match data["bound"].as_str().unwrap() {
             "O" => "outbound",
             "I" => "inbound",
@@ -317,8 +754,8 @@ async fn load_routes() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                 route: route.to_string(),
                 service_type,
                 direction: bound.to_string(),
-                orig: orig_tc.to_string(),
-                dest: dest_tc.to_string(),
+                orig: orig.to_string(),
+                dest: dest.to_string(),
             });
         } else {
             mutex_routes.insert(
@@ -327,13 +764,149 @@ async fn load_routes() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                     route: route.to_string(),
                     service_type,
                     direction: bound.to_string(),
-                    orig: orig_tc.to_string(),
-                    dest: dest_tc.to_string(),
+                    orig: orig.to_string(),
+                    dest: dest.to_string(),
                 }],
             );
         }
     });
 
+    if !no_cache {
+        write_cache(&cache_file, &*mutex_routes);
+    }
+
+    Ok(())
+}
+
+/// Download the bulk `route-stop` listing and build the inverted
+/// `stop_id -> serving routes` index used by the `stop` command. Cached on disk
+/// like the other directory data since it changes rarely.
+async fn load_stop_routes(no_cache: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if !no_cache {
+        if let Some(map) =
+            read_cache::<HashMap<String, Vec<StopRoute>>>(Cache::STOP_ROUTES_FILE, Cache::TTL)
+        {
+            *STOP_ROUTES.lock().unwrap() = map;
+            return Ok(());
+        }
+    }
+
+    let req_url = format!("{}/{}", HKGovAPI::BASE_URL, HKGovAPI::ROUTE_STOP_URL);
+
+    let body = REQWEST_CLIENT
+        .get(req_url)
+        .send()
+        .await?
+        .json::<serde_json::Value>()
+        .await?;
+
+    let mut mutex_stop_routes = STOP_ROUTES.lock().unwrap();
+    body["data"]
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .for_each(|data| {
+            let stop_id = data["stop"].as_str().unwrap();
+            let route = data["route"].as_str().unwrap();
+            let service_type = data["service_type"]
+                .as_str()
+                .unwrap()
+                .parse::<i64>()
+                .unwrap();
+            let seq = data["seq"].as_str().unwrap().parse::<i64>().unwrap();
+            let direction = match data["bound"].as_str().unwrap() {
+                "O" => "outbound",
+                "I" => "inbound",
+                _ => "",
+            };
+
+            mutex_stop_routes
+                .entry(stop_id.to_string())
+                .or_default()
+                .push(StopRoute {
+                    route: route.to_string(),
+                    direction: direction.to_string(),
+                    service_type,
+                    seq,
+                });
+        });
+
+    if !no_cache {
+        write_cache(Cache::STOP_ROUTES_FILE, &*mutex_stop_routes);
+    }
+
+    Ok(())
+}
+
+/// Fuzzy-match `query` against every loaded stop name and, for the best
+/// matches, list every route that serves that stop. Ranking is ascending by
+/// Levenshtein distance, ties broken by where the query appears in the name.
+async fn search_stop(
+    query: &str,
+    no_cache: bool,
+    format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    load_stop_routes(no_cache).await?;
+
+    let mutex_stop_routes = STOP_ROUTES.lock().unwrap();
+    let mutex_stop_id_names = STOP_ID_NAMES.lock().unwrap();
+
+    let needle = query.to_lowercase();
+    let mut ranked: Vec<(&StopIdName, usize, usize)> = mutex_stop_id_names
+        .values()
+        .map(|stop| {
+            let haystack = stop.stop_name.to_lowercase();
+            let distance = levenshtein(&needle, &haystack);
+            let position = haystack.find(&needle).unwrap_or(usize::MAX);
+            (stop, distance, position)
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| a.1.cmp(&b.1).then(a.2.cmp(&b.2)));
+
+    let mut output = vec![];
+    for (stop, _, _) in ranked.into_iter().take(STOP_SEARCH_LIMIT) {
+        let routes = mutex_stop_routes.get(&stop.stop_id).cloned().unwrap_or_default();
+        if routes.is_empty() {
+            output.push(StopRouteInfo {
+                stop_id: stop.stop_id.clone(),
+                stop_name: stop.stop_name.clone(),
+                route: "".to_string(),
+                direction: "".to_string(),
+                service_type: 0,
+                seq: 0,
+            });
+            continue;
+        }
+        for r in routes {
+            output.push(StopRouteInfo {
+                stop_id: stop.stop_id.clone(),
+                stop_name: stop.stop_name.clone(),
+                route: r.route,
+                direction: r.direction,
+                service_type: r.service_type,
+                seq: r.seq,
+            });
+        }
+    }
+
+    match format {
+        OutputFormat::Json => emit_json(&output)?,
+        OutputFormat::Csv => emit_csv(&output)?,
+        OutputFormat::Table => {
+            let mut table = Table::new(output);
+            table.with(
+                tabled::Style::modern()
+                    .off_horizontal()
+                    .horizontals([HorizontalLine::new(
+                        1,
+                        tabled::Style::modern().get_horizontal(),
+                    )]),
+            );
+            println!("{}", table);
+        }
+    }
+
     Ok(())
 }
 
@@ -342,6 +915,7 @@ async fn search_route_info(
     to_print: bool,
     direction: Option<&str>,
     service_type: Option<i64>,
+    format: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mutex_routes = ROUTES.lock().unwrap();
 
@@ -368,21 +942,27 @@ async fn search_route_info(
         return Ok(());
     }
 
-    let mut table = Table::new(route_info);
-    table.with(
-        tabled::Style::modern()
-            .off_horizontal()
-            .horizontals([HorizontalLine::new(
-                1,
-                tabled::Style::modern().get_horizontal(),
-            )]),
-    );
-    println!("{}", table);
+    match format {
+        OutputFormat::Json => emit_json(&route_info)?,
+        OutputFormat::Csv => emit_csv(&route_info)?,
+        OutputFormat::Table => {
+            let mut table = Table::new(route_info);
+            table.with(
+                tabled::Style::modern()
+                    .off_horizontal()
+                    .horizontals([HorizontalLine::new(
+                        1,
+                        tabled::Style::modern().get_horizontal(),
+                    )]),
+            );
+            println!("{}", table);
+        }
+    }
 
     Ok(())
 }
 
-fn search_all_route_info() {
+fn search_all_route_info(format: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
     let mutex_routes = ROUTES.lock().unwrap();
     let all_route_info = mutex_routes
         .iter()
@@ -391,28 +971,52 @@ fn search_all_route_info() {
             cur
         });
 
-    let mut table = Table::new(all_route_info);
-    table.with(
-        tabled::Style::modern()
-            .off_horizontal()
-            .off_top()
-            .off_bottom(),
-    );
+    match format {
+        OutputFormat::Json => emit_json(&all_route_info)?,
+        OutputFormat::Csv => emit_csv(&all_route_info)?,
+        OutputFormat::Table => {
+            let mut table = Table::new(all_route_info);
+            table.with(
+                tabled::Style::modern()
+                    .off_horizontal()
+                    .off_top()
+                    .off_bottom(),
+            );
+            println!("{}", table);
+        }
+    }
 
-    println!("{}", table);
+    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
+    // `cache` management never needs the network — handle it before loading.
+    if let Commands::Cache { action } = &cli.command {
+        match action {
+            CacheCommands::Clear => {
+                clear_cache()?;
+                println!("cache cleared");
+            }
+        }
+        return Ok(());
+    }
+
+    let no_cache = cli.no_cache;
+    let format = cli.format;
+    let lang = cli.lang;
+    // honor `--no-color` and fall back to plain output when piped
+    let color = !cli.no_color && std::io::stdout().is_terminal();
+
     let task_load_names = tokio::spawn(async move {
-        load_names().await?;
+        load_names(no_cache, lang).await?;
         Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
     });
 
     let task_load_routes = tokio::spawn(async move {
-        load_routes().await?;
+        load_routes(no_cache, lang).await?;
         Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
     });
 
@@ -423,7 +1027,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match cli.command {
         Commands::Route { route } => {
-            search_route_info(&route.to_uppercase(), true, None, None).await?;
+            search_route_info(&route.to_uppercase(), true, None, None, format).await?;
         }
 
         Commands::Eta {
@@ -431,6 +1035,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             inbound,
             outbound,
             service_type,
+            watch,
+            interval,
         } => {
             if inbound == outbound {
                 Err("Please set exactly one of `inbound` or `outbound` to `true`")?;
@@ -444,12 +1050,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 }
             };
 
-            search_route_eta(&route.to_uppercase(), direction, service_type).await?;
+            if watch {
+                watch_route_eta(
+                    &route.to_uppercase(),
+                    direction,
+                    service_type,
+                    interval,
+                    format,
+                    color,
+                )
+                .await?;
+            } else {
+                search_route_eta(&route.to_uppercase(), direction, service_type, format, color)
+                    .await?;
+            }
         }
 
         Commands::All => {
-            search_all_route_info();
+            search_all_route_info(format)?;
         }
+
+        Commands::Stop { query } => {
+            search_stop(&query, no_cache, format).await?;
+        }
+
+        // handled before loading above
+        Commands::Cache { .. } => unreachable!(),
     }
 
     if cli.debug {